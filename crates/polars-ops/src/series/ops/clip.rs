@@ -2,12 +2,26 @@ use polars_core::prelude::arity::{binary_elementwise, ternary_elementwise, unary
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 
+/// How a null per-row bound is treated by [`clip`], [`clip_min`], and [`clip_max`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum NullBoundPolicy {
+    /// A null bound imposes no restriction on that side; the value passes through unchanged.
+    #[default]
+    Ignore,
+    /// A null bound produces a null output for that row.
+    Propagate,
+    /// Fail if any bound is null.
+    Error,
+}
+
 /// Set values outside the given boundaries to the boundary value.
-pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
-        InvalidOperation: "`clip` only supports physical numeric types"
-    );
+pub fn clip(
+    s: &Series,
+    min: &Series,
+    max: &Series,
+    null_bound_policy: NullBoundPolicy,
+) -> PolarsResult<Series> {
+    ensure_bounds_non_null(null_bound_policy, &[min, max])?;
     let n = [s.len(), min.len(), max.len()]
         .into_iter()
         .find(|l| *l != 1)
@@ -27,6 +41,14 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
         );
     }
 
+    if is_string_like(s.dtype()) {
+        return clip_string(s, Some(min), Some(max), null_bound_policy);
+    }
+    polars_ensure!(
+        s.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "`clip` only supports numeric, temporal, and string types"
+    );
+
     let original_type = s.dtype();
     let (min, max) = (min.strict_cast(s.dtype())?, max.strict_cast(s.dtype())?);
 
@@ -40,7 +62,7 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
         let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
         let min: &ChunkedArray<$T> = min.as_ref().as_ref().as_ref();
         let max: &ChunkedArray<$T> = max.as_ref().as_ref().as_ref();
-        let out = clip_helper_both_bounds(ca, min, max).into_series();
+        let out = clip_helper_both_bounds(ca, min, max, null_bound_policy).into_series();
         match original_type {
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => {
@@ -54,17 +76,25 @@ pub fn clip(s: &Series, min: &Series, max: &Series) -> PolarsResult<Series> {
 }
 
 /// Set values above the given maximum to the maximum value.
-pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
-        InvalidOperation: "`clip` only supports physical numeric types"
-    );
+pub fn clip_max(
+    s: &Series,
+    max: &Series,
+    null_bound_policy: NullBoundPolicy,
+) -> PolarsResult<Series> {
+    ensure_bounds_non_null(null_bound_policy, &[max])?;
     polars_ensure!(
         s.len() == max.len() || s.len() == 1 || max.len() == 1,
         length_mismatch = "clip(max)",
         s.len(),
         max.len()
     );
+    if is_string_like(s.dtype()) {
+        return clip_string(s, None, Some(max), null_bound_policy);
+    }
+    polars_ensure!(
+        s.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "`clip` only supports numeric, temporal, and string types"
+    );
 
     let original_type = s.dtype();
     let max = max.strict_cast(s.dtype())?;
@@ -74,7 +104,7 @@ pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
     with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
         let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
         let max: &ChunkedArray<$T> = max.as_ref().as_ref().as_ref();
-        let out = clip_helper_single_bound(ca, max, num_traits::clamp_max).into_series();
+        let out = clip_helper_single_bound(ca, max, BoundSide::Max, null_bound_policy).into_series();
         match original_type {
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => {
@@ -88,17 +118,25 @@ pub fn clip_max(s: &Series, max: &Series) -> PolarsResult<Series> {
 }
 
 /// Set values below the given minimum to the minimum value.
-pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
-    polars_ensure!(
-        s.dtype().to_physical().is_primitive_numeric(),
-        InvalidOperation: "`clip` only supports physical numeric types"
-    );
+pub fn clip_min(
+    s: &Series,
+    min: &Series,
+    null_bound_policy: NullBoundPolicy,
+) -> PolarsResult<Series> {
+    ensure_bounds_non_null(null_bound_policy, &[min])?;
     polars_ensure!(
         s.len() == min.len() || s.len() == 1 || min.len() == 1,
         length_mismatch = "clip(min)",
         s.len(),
         min.len()
     );
+    if is_string_like(s.dtype()) {
+        return clip_string(s, Some(min), None, null_bound_policy);
+    }
+    polars_ensure!(
+        s.dtype().to_physical().is_primitive_numeric(),
+        InvalidOperation: "`clip` only supports numeric, temporal, and string types"
+    );
 
     let original_type = s.dtype();
     let min = min.strict_cast(s.dtype())?;
@@ -108,7 +146,7 @@ pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
     with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
         let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
         let min: &ChunkedArray<$T> = min.as_ref().as_ref().as_ref();
-        let out = clip_helper_single_bound(ca, min, num_traits::clamp_min).into_series();
+        let out = clip_helper_single_bound(ca, min, BoundSide::Min, null_bound_policy).into_series();
         match original_type {
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => {
@@ -121,90 +159,330 @@ pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
     })
 }
 
+/// Clamp a numeric Series to data-derived quantile cutoffs.
+///
+/// The lower cutoff is the `lower_quantile` and the upper cutoff the `upper_quantile` of the
+/// non-null values in `s`, computed with the same linear interpolation used elsewhere. The
+/// cutoffs are fractional (`Float64`), so the series is clamped as `Float64` to preserve them; the
+/// two cutoffs are broadcast as length-1 bounds and fed through the regular [`clip`] machinery, so
+/// nulls are preserved exactly as in `clip`.
+pub fn winsorize(s: &Series, lower_quantile: f64, upper_quantile: f64) -> PolarsResult<Series> {
+    polars_ensure!(
+        lower_quantile <= upper_quantile,
+        InvalidOperation: "`winsorize` requires `lower_quantile` <= `upper_quantile`, got {} > {}",
+        lower_quantile,
+        upper_quantile
+    );
+
+    let lower = s
+        .quantile_reduce(lower_quantile, QuantileMethod::Linear)?
+        .into_series(s.name().clone());
+    let upper = s
+        .quantile_reduce(upper_quantile, QuantileMethod::Linear)?
+        .into_series(s.name().clone());
+
+    // The quantile cutoffs are `Float64`; clamp on a float view so a fractional cutoff (e.g. the
+    // `0.05` quantile of an integer column) is neither rejected nor truncated by the strict cast in
+    // `clip`.
+    let s = s.cast(&DataType::Float64)?;
+    clip(&s, &lower, &upper, NullBoundPolicy::default())
+}
+
+/// Which side of the clamp a bound applies to.
+#[derive(Copy, Clone)]
+enum BoundSide {
+    Min,
+    Max,
+}
+
+fn ensure_bounds_non_null(policy: NullBoundPolicy, bounds: &[&Series]) -> PolarsResult<()> {
+    if policy == NullBoundPolicy::Error {
+        for b in bounds {
+            polars_ensure!(
+                b.null_count() == 0,
+                InvalidOperation: "`clip` received a null bound under the `Error` null-bound policy"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Clamp a single value against one optional bound, honouring the null-bound policy.
+#[inline]
+fn clamp_side<N>(v: N, bound: Option<N>, side: BoundSide, policy: NullBoundPolicy) -> Option<N>
+where
+    N: PartialOrd + Copy,
+{
+    match bound {
+        Some(b) => Some(match side {
+            BoundSide::Min => num_traits::clamp_min(v, b),
+            BoundSide::Max => num_traits::clamp_max(v, b),
+        }),
+        // `Error` is rejected up front, so only `Ignore`/`Propagate` reach here.
+        None => match policy {
+            NullBoundPolicy::Propagate => None,
+            _ => Some(v),
+        },
+    }
+}
+
+/// Clamp a single value between two optional bounds, honouring the null-bound policy.
+#[inline]
+fn clamp_both<N>(v: N, min: Option<N>, max: Option<N>, policy: NullBoundPolicy) -> Option<N>
+where
+    N: PartialOrd + Copy,
+{
+    let v = clamp_side(v, min, BoundSide::Min, policy)?;
+    clamp_side(v, max, BoundSide::Max, policy)
+}
+
+fn is_string_like(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::String => true,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => true,
+        _ => false,
+    }
+}
+
+/// Clamp `String`/`Categorical` values by their lexical ordering.
+///
+/// The values and bounds are viewed through their string representation and clamped with the same
+/// `PartialOrd` ordering used in comparisons, then the original dtype is reconstructed exactly like
+/// the `Decimal` branch in [`clip`]. Null bounds are handled according to `policy`.
+fn clip_string(
+    s: &Series,
+    min: Option<&Series>,
+    max: Option<&Series>,
+    policy: NullBoundPolicy,
+) -> PolarsResult<Series> {
+    let original_type = s.dtype();
+
+    // Clamping happens on the string representation, so only dtypes whose comparison ordering *is*
+    // the lexical string ordering are accepted. `Enum` orders by category-definition order, and a
+    // physical-ordered `Categorical` orders by category code, so both would disagree with `<`/`>`
+    // on the same column and are rejected.
+    #[cfg(feature = "dtype-categorical")]
+    match original_type {
+        DataType::Enum(_, _) => polars_bail!(
+            InvalidOperation: "`clip` does not support `Enum`, whose comparison order is not lexical"
+        ),
+        DataType::Categorical(_, ordering) if *ordering != CategoricalOrdering::Lexical => {
+            polars_bail!(
+                InvalidOperation: "`clip` only supports lexically-ordered `Categorical`; a physical-ordered categorical compares by category code"
+            )
+        },
+        _ => {},
+    }
+
+    let cast_str = |b: Option<&Series>| -> PolarsResult<Option<Series>> {
+        b.map(|b| b.cast(&DataType::String)).transpose()
+    };
+    let s_str = s.cast(&DataType::String)?;
+    let min = cast_str(min)?;
+    let max = cast_str(max)?;
+
+    let ca = s_str.str()?;
+    let min = min.as_ref().map(|s| s.str()).transpose()?;
+    let max = max.as_ref().map(|s| s.str()).transpose()?;
+
+    let out = clip_string_helper(ca, min, max, policy).into_series();
+    if original_type == &DataType::String {
+        Ok(out)
+    } else {
+        out.cast(original_type)
+    }
+}
+
+fn clip_string_helper<'a>(
+    ca: &'a StringChunked,
+    min: Option<&'a StringChunked>,
+    max: Option<&'a StringChunked>,
+    policy: NullBoundPolicy,
+) -> StringChunked {
+    // Broadcast a length-1 value side, as well as length-1 bounds, up to the common length.
+    let n = [ca.len(), min.map_or(1, |b| b.len()), max.map_or(1, |b| b.len())]
+        .into_iter()
+        .find(|l| *l != 1)
+        .unwrap_or(1);
+    let value_at = |i: usize| if ca.len() == 1 { ca.get(0) } else { ca.get(i) };
+
+    let mut out: StringChunked = (0..n)
+        .map(|i| {
+            value_at(i).and_then(|v| {
+                let v = clip_string_side(v, min, i, BoundSide::Min, policy)?;
+                clip_string_side(v, max, i, BoundSide::Max, policy)
+            })
+        })
+        .collect();
+    out.rename(ca.name().clone());
+    out
+}
+
+/// Clamp a string value against one optional bound series, honouring the null-bound policy.
+///
+/// A bound series is either length 1 (broadcast) or the same length as the values; an absent bound
+/// leaves that side unclamped, whereas a present-but-null entry follows `policy`.
+#[inline]
+fn clip_string_side<'a>(
+    v: &'a str,
+    bound: Option<&'a StringChunked>,
+    i: usize,
+    side: BoundSide,
+    policy: NullBoundPolicy,
+) -> Option<&'a str> {
+    let Some(bound) = bound else {
+        return Some(v);
+    };
+    let b = if bound.len() == 1 {
+        bound.get(0)
+    } else {
+        bound.get(i)
+    };
+    match b {
+        Some(b) => Some(match side {
+            BoundSide::Min if v < b => b,
+            BoundSide::Max if v > b => b,
+            _ => v,
+        }),
+        None => match policy {
+            NullBoundPolicy::Propagate => None,
+            _ => Some(v),
+        },
+    }
+}
+
 fn clip_helper_both_bounds<T>(
     ca: &ChunkedArray<T>,
     min: &ChunkedArray<T>,
     max: &ChunkedArray<T>,
+    policy: NullBoundPolicy,
 ) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     T::Native: PartialOrd,
 {
     match (min.len(), max.len()) {
-        (1, 1) => match (min.get(0), max.get(0)) {
-            (Some(min), Some(max)) => clip_unary(ca, |v| num_traits::clamp(v, min, max)),
-            (Some(min), None) => clip_unary(ca, |v| num_traits::clamp_min(v, min)),
-            (None, Some(max)) => clip_unary(ca, |v| num_traits::clamp_max(v, max)),
-            (None, None) => ca.clone(),
+        (1, 1) => {
+            let (lo, hi) = (min.get(0), max.get(0));
+            unary_elementwise(ca, |opt_v| opt_v.and_then(|v| clamp_both(v, lo, hi, policy)))
         },
-        (1, _) => match min.get(0) {
-            Some(min) => clip_binary(ca, max, |v, b| num_traits::clamp(v, min, b)),
-            None => clip_binary(ca, max, num_traits::clamp_max),
+        (1, _) => {
+            let lo = min.get(0);
+            binary_elementwise(ca, max, |opt_v, opt_hi| {
+                opt_v.and_then(|v| clamp_both(v, lo, opt_hi, policy))
+            })
         },
-        (_, 1) => match max.get(0) {
-            Some(max) => clip_binary(ca, min, |v, b| num_traits::clamp(v, b, max)),
-            None => clip_binary(ca, min, num_traits::clamp_min),
+        (_, 1) => {
+            let hi = max.get(0);
+            binary_elementwise(ca, min, |opt_v, opt_lo| {
+                opt_v.and_then(|v| clamp_both(v, opt_lo, hi, policy))
+            })
         },
-        _ => clip_ternary(ca, min, max),
+        _ => ternary_elementwise(ca, min, max, |opt_v, opt_lo, opt_hi| {
+            opt_v.and_then(|v| clamp_both(v, opt_lo, opt_hi, policy))
+        }),
     }
 }
 
-fn clip_helper_single_bound<T, F>(
+fn clip_helper_single_bound<T>(
     ca: &ChunkedArray<T>,
     bound: &ChunkedArray<T>,
-    op: F,
+    side: BoundSide,
+    policy: NullBoundPolicy,
 ) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
     T::Native: PartialOrd,
-    F: Fn(T::Native, T::Native) -> T::Native,
 {
     match bound.len() {
-        1 => match bound.get(0) {
-            Some(bound) => clip_unary(ca, |v| op(v, bound)),
-            None => ca.clone(),
+        1 => {
+            let b = bound.get(0);
+            unary_elementwise(ca, |opt_v| opt_v.and_then(|v| clamp_side(v, b, side, policy)))
         },
-        _ => clip_binary(ca, bound, op),
+        _ => binary_elementwise(ca, bound, |opt_v, opt_b| {
+            opt_v.and_then(|v| clamp_side(v, opt_b, side, policy))
+        }),
     }
 }
 
-fn clip_unary<T, F>(ca: &ChunkedArray<T>, op: F) -> ChunkedArray<T>
-where
-    T: PolarsNumericType,
-    F: Fn(T::Native) -> T::Native + Copy,
-{
-    unary_elementwise(ca, |v| v.map(op))
-}
+#[cfg(test)]
+mod test {
+    use super::*;
 
-fn clip_binary<T, F>(ca: &ChunkedArray<T>, bound: &ChunkedArray<T>, op: F) -> ChunkedArray<T>
-where
-    T: PolarsNumericType,
-    T::Native: PartialOrd,
-    F: Fn(T::Native, T::Native) -> T::Native,
-{
-    binary_elementwise(ca, bound, |opt_s, opt_bound| match (opt_s, opt_bound) {
-        (Some(s), Some(bound)) => Some(op(s, bound)),
-        (Some(s), None) => Some(s),
-        (None, _) => None,
-    })
-}
+    fn i32_vec(s: &Series) -> Vec<Option<i32>> {
+        s.i32().unwrap().into_iter().collect()
+    }
 
-fn clip_ternary<T>(
-    ca: &ChunkedArray<T>,
-    min: &ChunkedArray<T>,
-    max: &ChunkedArray<T>,
-) -> ChunkedArray<T>
-where
-    T: PolarsNumericType,
-    T::Native: PartialOrd,
-{
-    ternary_elementwise(ca, min, max, |opt_v, opt_min, opt_max| {
-        match (opt_v, opt_min, opt_max) {
-            (Some(v), Some(min), Some(max)) => Some(num_traits::clamp(v, min, max)),
-            (Some(v), Some(min), None) => Some(num_traits::clamp_min(v, min)),
-            (Some(v), None, Some(max)) => Some(num_traits::clamp_max(v, max)),
-            (Some(v), None, None) => Some(v),
-            (None, _, _) => None,
-        }
-    })
+    #[test]
+    fn test_clip_scalar_bounds() {
+        let s = Series::new("a".into(), &[1, 5, 10, 15]);
+        let min = Series::new("min".into(), &[3]);
+        let max = Series::new("max".into(), &[12]);
+        let out = clip(&s, &min, &max, NullBoundPolicy::default()).unwrap();
+        assert_eq!(i32_vec(&out), [Some(3), Some(5), Some(10), Some(12)]);
+    }
+
+    #[test]
+    fn test_clip_length_mismatch() {
+        let s = Series::new("a".into(), &[1, 2, 3]);
+        let min = Series::new("min".into(), &[0, 0]);
+        let max = Series::new("max".into(), &[9]);
+        assert!(clip(&s, &min, &max, NullBoundPolicy::default()).is_err());
+    }
+
+    #[test]
+    fn test_null_bound_policy() {
+        let s = Series::new("a".into(), &[1, 5, 10]);
+        let min = Series::new("min".into(), &[Some(2), None, Some(8)]);
+        let max = Series::new("max".into(), &[9, 9, 9]);
+
+        let ignore = clip(&s, &min, &max, NullBoundPolicy::Ignore).unwrap();
+        assert_eq!(i32_vec(&ignore), [Some(2), Some(5), Some(9)]);
+
+        let propagate = clip(&s, &min, &max, NullBoundPolicy::Propagate).unwrap();
+        assert_eq!(i32_vec(&propagate), [Some(2), None, Some(9)]);
+
+        assert!(clip(&s, &min, &max, NullBoundPolicy::Error).is_err());
+    }
+
+    #[test]
+    fn test_clip_string() {
+        let s = Series::new("a".into(), &["apple", "mango", "zebra"]);
+        let min = Series::new("min".into(), &["banana"]);
+        let max = Series::new("max".into(), &["pear"]);
+        let out = clip(&s, &min, &max, NullBoundPolicy::default()).unwrap();
+        assert_eq!(
+            out.str().unwrap().into_iter().collect::<Vec<_>>(),
+            [Some("banana"), Some("mango"), Some("pear")]
+        );
+    }
+
+    #[test]
+    fn test_winsorize() {
+        let s = Series::new("a".into(), &[1, 2, 3, 4, 5]);
+        // The extreme quantiles are the series min/max, so the values are unchanged (but floated).
+        let out = winsorize(&s, 0.0, 1.0).unwrap();
+        assert_eq!(
+            out.f64().unwrap().into_iter().collect::<Vec<_>>(),
+            [Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)]
+        );
+    }
+
+    #[test]
+    fn test_winsorize_fractional_cutoffs() {
+        let s = Series::new("a".into(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        // Linear interpolation: the 0.1 quantile is 1.9 and the 0.9 quantile is 9.1.
+        let out = winsorize(&s, 0.1, 0.9).unwrap();
+        let f = out.f64().unwrap();
+        assert_eq!(f.get(0), Some(1.9));
+        assert_eq!(f.get(4), Some(5.0));
+        assert_eq!(f.get(9), Some(9.1));
+    }
+
+    #[test]
+    fn test_winsorize_invalid_quantiles() {
+        let s = Series::new("a".into(), &[1, 2, 3]);
+        assert!(winsorize(&s, 0.9, 0.1).is_err());
+    }
 }
@@ -0,0 +1,41 @@
+mod unpivot;
+
+use unpivot::process_unpivot;
+
+use super::*;
+
+impl PredicatePushDown<'_> {
+    /// Push predicates through a `FunctionIR` node, dispatching to the per-function handlers.
+    pub(super) fn process_function(
+        &mut self,
+        lp: IR,
+        input: Node,
+        function: &FunctionIR,
+        acc_predicates: PlHashMap<PlSmallStr, ExprIR>,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> PolarsResult<IR> {
+        match function {
+            FunctionIR::Unpivot { args, .. } => {
+                process_unpivot(self, lp, args, input, acc_predicates, lp_arena, expr_arena)
+            },
+            // Functions that are not known to commute with a filter block the pushdown: the
+            // predicates are re-applied above the node.
+            _ => {
+                let lp = self.pushdown_and_continue(
+                    lp,
+                    Default::default(),
+                    lp_arena,
+                    expr_arena,
+                    false,
+                )?;
+                Ok(self.optional_apply_predicate(
+                    lp,
+                    acc_predicates.into_values().collect(),
+                    lp_arena,
+                    expr_arena,
+                ))
+            },
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Predicate pushdown for `unpivot` nodes.
+//!
+//! Dispatched from the `FunctionIR::Unpivot { args, .. }` arm of the predicate-pushdown function
+//! handler in `functions/mod.rs`, the same place the projection-pushdown twin reaches its own
+//! `process_unpivot`.
+
+use super::*;
+
+pub(super) fn process_unpivot(
+    opt: &mut PredicatePushDown,
+    lp: IR,
+    args: &Arc<UnpivotArgsIR>,
+    input: Node,
+    mut acc_predicates: PlHashMap<PlSmallStr, ExprIR>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    // The identifier columns in `args.index` survive the unpivot unchanged, so any predicate that
+    // references only those columns can be pushed into `input` before unpivoting. Predicates that
+    // touch the generated `variable`/`value` columns have to stay above. This mirrors the
+    // projection-splitting logic in the projection-pushdown twin of this file.
+    let index: PlHashSet<PlSmallStr> = args.index.iter().cloned().collect();
+
+    let mut pushdown = PlHashMap::with_capacity(acc_predicates.len());
+    let mut local = Vec::new();
+    for (name, predicate) in acc_predicates.drain() {
+        let pushable =
+            aexpr_to_leaf_names_iter(predicate.node(), expr_arena).all(|col| index.contains(&col));
+        if pushable {
+            pushdown.insert(name, predicate);
+        } else {
+            local.push(predicate);
+        }
+    }
+
+    opt.pushdown_and_assign(input, pushdown, lp_arena, expr_arena)?;
+
+    // re-make unpivot node so that the schema is updated
+    let lp = IRBuilder::new(input, expr_arena, lp_arena)
+        .unpivot(args.clone())
+        .build();
+
+    Ok(opt.optional_apply_predicate(lp, local, lp_arena, expr_arena))
+}